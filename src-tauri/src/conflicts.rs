@@ -0,0 +1,289 @@
+//! Detection and merging of courses that occupy the same timetable slot.
+
+use crate::pdf_reader::Course;
+use crate::weeks::format_weeks;
+use std::collections::HashMap;
+
+/// One atomic (day, section, week) slot a course occupies.
+struct Unit {
+    day: u8,
+    section: u8,
+    week: u8,
+    course_idx: usize,
+}
+
+fn find(parent: &mut [usize], x: usize) -> usize {
+    if parent[x] != x {
+        parent[x] = find(parent, parent[x]);
+    }
+    parent[x]
+}
+
+fn union(parent: &mut [usize], a: usize, b: usize) {
+    let ra = find(parent, a);
+    let rb = find(parent, b);
+    if ra != rb {
+        parent[ra] = rb;
+    }
+}
+
+/// Join two `|`-separated field values, skipping `incoming` if it already
+/// appears among `existing`'s parts.
+fn join_distinct(existing: &str, incoming: &str) -> String {
+    if incoming.is_empty() || existing.split('|').any(|part| part == incoming) {
+        existing.to_string()
+    } else if existing.is_empty() {
+        incoming.to_string()
+    } else {
+        format!("{}|{}", existing, incoming)
+    }
+}
+
+fn merge_group(courses: &[Course], members: &[usize]) -> Course {
+    let mut merged = courses[members[0]].clone();
+    let mut weeks: Vec<u8> = merged.weeks_expanded();
+    for &idx in &members[1..] {
+        let other = &courses[idx];
+        merged.name = join_distinct(&merged.name, &other.name);
+        merged.teacher = join_distinct(&merged.teacher, &other.teacher);
+        merged.location = join_distinct(&merged.location, &other.location);
+        merged.start_section = merged.start_section.min(other.start_section);
+        merged.end_section = merged.end_section.max(other.end_section);
+        weeks.extend(other.weeks_expanded());
+    }
+    weeks.sort_unstable();
+    weeks.dedup();
+    merged.weeks = format_weeks(&weeks);
+    merged
+}
+
+/// Merge courses that share the same `day_of_week`, an overlapping section
+/// range, and at least one common week into a single entry.
+///
+/// Each course is first exploded into atomic (day, single section, single
+/// week) units. The units are sorted by `(day_of_week, start_section, week)`
+/// and adjacent units that land on the same slot identify conflicting
+/// courses, which are then combined via a union-find so transitive overlaps
+/// (A overlaps B, B overlaps C) end up in one merged entry. Merged fields are
+/// joined with `|`, skipping values that already appear.
+///
+/// Note: when two courses only *partially* overlap in section range (e.g.
+/// sections 1-2 vs. 2-3), the merged entry's section range is widened to
+/// cover both (`min(start)..max(end)`), even though the non-overlapping ends
+/// were only ever taught under one of the original names. Consumers of the
+/// merged entry (e.g. the HTML grid) render the widened range for every week
+/// in the merged week set, which is the union of the members' weeks.
+pub fn merge_conflicts(courses: Vec<Course>) -> Vec<Course> {
+    if courses.is_empty() {
+        return courses;
+    }
+
+    let mut units = Vec::new();
+    for (course_idx, course) in courses.iter().enumerate() {
+        for week in course.weeks_expanded() {
+            for section in course.start_section..=course.end_section {
+                units.push(Unit {
+                    day: course.day_of_week,
+                    section,
+                    week,
+                    course_idx,
+                });
+            }
+        }
+    }
+    units.sort_by_key(|u| (u.day, u.section, u.week));
+
+    let mut parent: Vec<usize> = (0..courses.len()).collect();
+    for pair in units.windows(2) {
+        let (a, b) = (&pair[0], &pair[1]);
+        if (a.day, a.section, a.week) == (b.day, b.section, b.week) {
+            union(&mut parent, a.course_idx, b.course_idx);
+        }
+    }
+
+    let mut group_order = Vec::new();
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for idx in 0..courses.len() {
+        let root = find(&mut parent, idx);
+        groups.entry(root).or_insert_with(|| {
+            group_order.push(root);
+            Vec::new()
+        });
+        groups.get_mut(&root).unwrap().push(idx);
+    }
+
+    group_order
+        .into_iter()
+        .map(|root| merge_group(&courses, &groups[&root]))
+        .collect()
+}
+
+/// Group courses by transitive (`day_of_week`, `section_range`) overlap,
+/// ignoring weeks entirely. Returns each group as a list of indices into
+/// `courses`, in first-seen order.
+///
+/// `merge_conflicts` only merges courses that also share a week, so courses
+/// that never meet in the same week stay separate. Renderers that lay out a single static grid
+/// regardless of week (e.g. the HTML weekly grid) need every overlapping
+/// course accounted for in one cell even when they don't share a week, so
+/// they should group with this instead of relying on `merge_conflicts` alone.
+pub fn group_by_section_overlap(courses: &[Course]) -> Vec<Vec<usize>> {
+    if courses.is_empty() {
+        return Vec::new();
+    }
+
+    let mut parent: Vec<usize> = (0..courses.len()).collect();
+    for i in 0..courses.len() {
+        for j in (i + 1)..courses.len() {
+            let (a, b) = (&courses[i], &courses[j]);
+            if a.day_of_week != b.day_of_week {
+                continue;
+            }
+            let (ra, rb) = (a.section_range(), b.section_range());
+            if ra.start() <= rb.end() && rb.start() <= ra.end() {
+                union(&mut parent, i, j);
+            }
+        }
+    }
+
+    let mut group_order = Vec::new();
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for idx in 0..courses.len() {
+        let root = find(&mut parent, idx);
+        groups.entry(root).or_insert_with(|| {
+            group_order.push(root);
+            Vec::new()
+        });
+        groups.get_mut(&root).unwrap().push(idx);
+    }
+
+    group_order.into_iter().map(|root| groups[&root].clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::CourseBuilder;
+
+    #[test]
+    fn leaves_non_overlapping_courses_alone() {
+        let courses = vec![
+            CourseBuilder::new("线性代数")
+                .teacher("张老师")
+                .weeks("1-8周")
+                .sections(1, 2)
+                .build(),
+            CourseBuilder::new("大学英语")
+                .teacher("李老师")
+                .location("B202")
+                .weeks("1-8周")
+                .day(2)
+                .sections(1, 2)
+                .build(),
+        ];
+        let mut merged = merge_conflicts(courses);
+        merged.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(merged.len(), 2);
+    }
+
+    #[test]
+    fn merges_courses_sharing_a_slot_and_week() {
+        let courses = vec![
+            CourseBuilder::new("线性代数")
+                .teacher("张老师")
+                .weeks("1-8周")
+                .sections(1, 2)
+                .build(),
+            CourseBuilder::new("离散数学")
+                .weeks("3-10周")
+                .sections(1, 2)
+                .build(),
+        ];
+        let merged = merge_conflicts(courses);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].name, "线性代数|离散数学");
+        assert_eq!(merged[0].teacher, "张老师|王老师");
+        assert_eq!(merged[0].weeks_expanded(), (1..=10).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn does_not_duplicate_identical_field_values() {
+        let courses = vec![
+            CourseBuilder::new("线性代数")
+                .teacher("张老师")
+                .weeks("1-8周")
+                .sections(1, 2)
+                .build(),
+            CourseBuilder::new("线性代数")
+                .teacher("张老师")
+                .weeks("3-10周")
+                .sections(1, 2)
+                .build(),
+        ];
+        let merged = merge_conflicts(courses);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].name, "线性代数");
+        assert_eq!(merged[0].teacher, "张老师");
+    }
+
+    #[test]
+    fn widens_section_range_on_partial_overlap() {
+        let courses = vec![
+            CourseBuilder::new("线性代数")
+                .teacher("张老师")
+                .weeks("1-8周")
+                .sections(1, 2)
+                .build(),
+            CourseBuilder::new("离散数学")
+                .weeks("1-8周")
+                .sections(2, 3)
+                .build(),
+        ];
+        let merged = merge_conflicts(courses);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].start_section, 1);
+        assert_eq!(merged[0].end_section, 3);
+    }
+
+    #[test]
+    fn groups_overlapping_courses_with_no_common_week() {
+        // merge_conflicts leaves these apart since they share no week, but
+        // their section ranges (1-3 vs. 2-4) overlap.
+        let courses = vec![
+            CourseBuilder::new("线性代数")
+                .teacher("张老师")
+                .weeks("1-8周")
+                .sections(1, 3)
+                .build(),
+            CourseBuilder::new("离散数学")
+                .weeks("9-16周")
+                .sections(2, 4)
+                .build(),
+        ];
+        assert_eq!(merge_conflicts(courses.clone()).len(), 2);
+
+        let groups = group_by_section_overlap(&courses);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].len(), 2);
+    }
+
+    #[test]
+    fn keeps_non_overlapping_courses_in_separate_groups() {
+        let courses = vec![
+            CourseBuilder::new("线性代数")
+                .teacher("张老师")
+                .weeks("1-8周")
+                .sections(1, 2)
+                .build(),
+            CourseBuilder::new("大学英语")
+                .teacher("李老师")
+                .location("B202")
+                .weeks("1-8周")
+                .day(2)
+                .sections(1, 2)
+                .build(),
+        ];
+        let groups = group_by_section_overlap(&courses);
+        assert_eq!(groups.len(), 2);
+    }
+}
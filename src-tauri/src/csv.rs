@@ -0,0 +1,141 @@
+//! CSV import/export of `Course` rows via the `csv` crate.
+
+use crate::pdf_reader::{Course, CourseSchedule};
+use csv::{ReaderBuilder, Terminator, WriterBuilder};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+/// The student/semester fields, as a one-row CSV record preceding the course
+/// rows. Routing these through `csv` (rather than hand-rolled
+/// `key=value,...` formatting) gets the same quoting/escaping guarantees the
+/// course rows already have, so a comma in e.g. `semester` round-trips.
+#[derive(Debug, Serialize, Deserialize)]
+struct Metadata {
+    student_name: String,
+    student_id: String,
+    semester: String,
+}
+
+fn writer_for<W: Write>(w: W) -> csv::Writer<W> {
+    WriterBuilder::new().terminator(Terminator::Any(b'\n')).from_writer(w)
+}
+
+/// Write a schedule as CSV: a one-row metadata record carrying the
+/// student/semester fields, a blank separator line, then one row per
+/// `Course`.
+pub fn write_csv(schedule: &CourseSchedule, mut w: impl Write) -> csv::Result<()> {
+    let metadata = Metadata {
+        student_name: schedule.student_name.clone(),
+        student_id: schedule.student_id.clone(),
+        semester: schedule.semester.clone(),
+    };
+    let mut meta_writer = writer_for(&mut w);
+    meta_writer.serialize(&metadata)?;
+    meta_writer.flush()?;
+    drop(meta_writer);
+
+    writeln!(w)?;
+
+    let mut writer = writer_for(w);
+    for course in &schedule.courses {
+        writer.serialize(course)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Read a schedule back from the format `write_csv` produces.
+pub fn read_csv(mut r: impl Read) -> csv::Result<CourseSchedule> {
+    let mut content = String::new();
+    r.read_to_string(&mut content)?;
+
+    let mut schedule = CourseSchedule::new();
+
+    // Read exactly one record for the metadata, then resume from the byte
+    // offset the reader left off at, rather than searching the raw string
+    // for a blank-line separator: a quoted metadata field may itself contain
+    // "\n\n", which would otherwise split the content in the wrong place.
+    let mut metadata_end = 0usize;
+    let mut meta_reader = ReaderBuilder::new().from_reader(content.as_bytes());
+    if let Some(record) = meta_reader.deserialize::<Metadata>().next() {
+        let metadata = record?;
+        schedule.student_name = metadata.student_name;
+        schedule.student_id = metadata.student_id;
+        schedule.semester = metadata.semester;
+        metadata_end = meta_reader.position().byte() as usize;
+    }
+    drop(meta_reader);
+
+    let courses_block = content[metadata_end..].trim_start_matches('\n');
+    let mut reader = ReaderBuilder::new().from_reader(courses_block.as_bytes());
+    for record in reader.deserialize() {
+        let course: Course = record?;
+        schedule.courses.push(course);
+    }
+
+    Ok(schedule)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::CourseBuilder;
+
+    fn course(name: &str) -> Course {
+        CourseBuilder::new(name).build()
+    }
+
+    #[test]
+    fn round_trips_courses_and_metadata() {
+        let mut schedule = CourseSchedule::new();
+        schedule.student_name = "张三".to_string();
+        schedule.student_id = "123456".to_string();
+        schedule.semester = "2026春".to_string();
+        schedule.courses = vec![course("高等数学"), course("大学英语")];
+
+        let mut buf = Vec::new();
+        write_csv(&schedule, &mut buf).unwrap();
+        let round_tripped = read_csv(buf.as_slice()).unwrap();
+
+        assert_eq!(round_tripped.student_name, schedule.student_name);
+        assert_eq!(round_tripped.student_id, schedule.student_id);
+        assert_eq!(round_tripped.semester, schedule.semester);
+        assert_eq!(round_tripped.courses, schedule.courses);
+    }
+
+    #[test]
+    fn round_trips_metadata_containing_commas() {
+        let mut schedule = CourseSchedule::new();
+        schedule.student_name = "张三,李四".to_string();
+        schedule.student_id = "123456".to_string();
+        schedule.semester = "2026春,秋".to_string();
+        schedule.courses = vec![course("高等数学")];
+
+        let mut buf = Vec::new();
+        write_csv(&schedule, &mut buf).unwrap();
+        let round_tripped = read_csv(buf.as_slice()).unwrap();
+
+        assert_eq!(round_tripped.student_name, schedule.student_name);
+        assert_eq!(round_tripped.semester, schedule.semester);
+        assert_eq!(round_tripped.courses, schedule.courses);
+    }
+
+    #[test]
+    fn round_trips_metadata_containing_a_blank_line() {
+        // A literal "\n\n" inside a quoted field used to be mistaken for the
+        // metadata/courses separator.
+        let mut schedule = CourseSchedule::new();
+        schedule.student_name = "张三\n\n李四".to_string();
+        schedule.student_id = "123456".to_string();
+        schedule.semester = "2026春".to_string();
+        schedule.courses = vec![course("高等数学"), course("大学英语")];
+
+        let mut buf = Vec::new();
+        write_csv(&schedule, &mut buf).unwrap();
+        let round_tripped = read_csv(buf.as_slice()).unwrap();
+
+        assert_eq!(round_tripped.student_name, schedule.student_name);
+        assert_eq!(round_tripped.semester, schedule.semester);
+        assert_eq!(round_tripped.courses, schedule.courses);
+    }
+}
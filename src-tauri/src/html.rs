@@ -0,0 +1,205 @@
+//! Rendering a `CourseSchedule` as a standalone, printable HTML weekly grid.
+
+use crate::conflicts::{group_by_section_overlap, merge_conflicts};
+use crate::pdf_reader::{Course, CourseSchedule};
+use std::collections::HashMap;
+
+const DAY_NAMES: [&str; 7] = [
+    "星期一", "星期二", "星期三", "星期四", "星期五", "星期六", "星期日",
+];
+
+const PALETTE: [&str; 8] = [
+    "#ffd6d6", "#ffe7c2", "#fff6c2", "#d9f7c2", "#c2f7e7", "#c2e0ff", "#d9c2ff", "#f7c2e7",
+];
+
+const STYLE: &str = "
+body { font-family: -apple-system, \"PingFang SC\", \"Microsoft YaHei\", sans-serif; margin: 2rem; }
+table { border-collapse: collapse; width: 100%; table-layout: fixed; }
+th, td { border: 1px solid #ccc; vertical-align: top; padding: 4px; text-align: center; }
+thead th { background: #f5f5f5; }
+td .course { border-radius: 4px; padding: 4px; text-align: left; }
+td .name { font-weight: bold; }
+td .meta { font-size: 0.8em; color: #444; }
+";
+
+/// Deterministic pastel color for a course name, so recurring courses are
+/// visually distinct without tracking any extra state.
+fn color_for(name: &str) -> &'static str {
+    let mut hash: u32 = 0;
+    for byte in name.bytes() {
+        hash = hash.wrapping_mul(31).wrapping_add(byte as u32);
+    }
+    PALETTE[hash as usize % PALETTE.len()]
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+fn render_cell(courses: &[&Course], rowspan: u8) -> String {
+    let mut cell = format!("<td rowspan=\"{}\">", rowspan);
+    for course in courses {
+        cell.push_str(&format!(
+            "<div class=\"course\" style=\"background:{}\"><div class=\"name\">{}</div><div class=\"meta\">{}</div><div class=\"meta\">{}</div><div class=\"meta\">{}</div></div>",
+            color_for(&course.name),
+            escape_html(&course.name),
+            escape_html(&course.teacher),
+            escape_html(&course.location),
+            escape_html(&course.weeks),
+        ));
+    }
+    cell.push_str("</td>");
+    cell
+}
+
+/// Render a `CourseSchedule` as a self-contained HTML page: a table with
+/// 星期一..星期日 columns and section rows. Courses whose section ranges
+/// overlap (even across different weeks, which `merge_conflicts` leaves
+/// separate) are grouped into one cell spanning the union of their ranges
+/// via `rowspan`, so none are silently dropped from the grid.
+pub fn schedule_to_html(schedule: &CourseSchedule) -> String {
+    let courses = merge_conflicts(schedule.courses.clone());
+    let groups = group_by_section_overlap(&courses);
+
+    let max_section = courses
+        .iter()
+        .map(|c| *c.section_range().end())
+        .max()
+        .unwrap_or(12)
+        .max(1);
+
+    let mut cells: HashMap<(u8, u8), (u8, Vec<&Course>)> = HashMap::new();
+    let mut covered: HashMap<(u8, u8), ()> = HashMap::new();
+    for group in &groups {
+        let members: Vec<&Course> = group.iter().map(|&idx| &courses[idx]).collect();
+        let day = members[0].day_of_week;
+        let start = members
+            .iter()
+            .map(|c| *c.section_range().start())
+            .min()
+            .unwrap();
+        let end = members
+            .iter()
+            .map(|c| *c.section_range().end())
+            .max()
+            .unwrap();
+
+        cells.insert((day, start), (end - start + 1, members));
+        for section in (start + 1)..=end {
+            covered.insert((day, section), ());
+        }
+    }
+
+    let mut body = String::new();
+    body.push_str("<table>\n<thead><tr><th>节次</th>");
+    for day in &DAY_NAMES {
+        body.push_str(&format!("<th>{}</th>", day));
+    }
+    body.push_str("</tr></thead>\n<tbody>\n");
+
+    for section in 1..=max_section {
+        body.push_str(&format!("<tr><th>{}</th>", section));
+        for day in 1u8..=7 {
+            if covered.contains_key(&(day, section)) {
+                continue;
+            }
+            match cells.get(&(day, section)) {
+                Some((rowspan, courses)) => body.push_str(&render_cell(courses, *rowspan)),
+                None => body.push_str("<td></td>"),
+            }
+        }
+        body.push_str("</tr>\n");
+    }
+    body.push_str("</tbody>\n</table>\n");
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"zh-CN\">\n<head><meta charset=\"UTF-8\"><title>{} 课表</title><style>{}</style></head>\n<body>\n<h1>{} {}</h1>\n{}</body>\n</html>\n",
+        escape_html(&schedule.student_name),
+        STYLE,
+        escape_html(&schedule.student_name),
+        escape_html(&schedule.semester),
+        body,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pdf_reader::CourseSchedule;
+    use crate::test_support::CourseBuilder;
+
+    #[test]
+    fn renders_course_name_and_table_structure() {
+        let schedule = CourseSchedule {
+            student_name: "张三".to_string(),
+            student_id: "123".to_string(),
+            semester: "2026春".to_string(),
+            courses: vec![CourseBuilder::new("高等数学").sections(1, 2).build()],
+            section_periods: Vec::new(),
+        };
+        let html = schedule_to_html(&schedule);
+        assert!(html.contains("<table>"));
+        assert!(html.contains("高等数学"));
+        assert!(html.contains("rowspan=\"2\""));
+    }
+
+    #[test]
+    fn render_cell_does_not_panic_on_inverted_section_range() {
+        let inverted = CourseBuilder::new("异常课程").sections(5, 3).build();
+        let cell = render_cell(&[&inverted], 1);
+        assert!(cell.contains("rowspan=\"1\""));
+    }
+
+    #[test]
+    fn schedule_to_html_still_renders_inverted_section_range() {
+        let schedule = CourseSchedule {
+            student_name: "张三".to_string(),
+            student_id: "123".to_string(),
+            semester: "2026春".to_string(),
+            courses: vec![CourseBuilder::new("异常课程").sections(5, 3).build()],
+            section_periods: Vec::new(),
+        };
+        let html = schedule_to_html(&schedule);
+        assert!(html.contains("异常课程"));
+        assert!(html.contains("A101"));
+    }
+
+    #[test]
+    fn schedule_to_html_merges_partially_overlapping_courses_instead_of_dropping_one() {
+        // Course A covers 1-3, course B starts mid-span at 2-4: without
+        // merging, A's rowspan marks section 2 as `covered` and B (keyed
+        // into `cells` at (day=1, section=2)) is skipped entirely.
+        let schedule = CourseSchedule {
+            student_name: "张三".to_string(),
+            student_id: "123".to_string(),
+            semester: "2026春".to_string(),
+            courses: vec![
+                CourseBuilder::new("课程A").sections(1, 3).build(),
+                CourseBuilder::new("课程B").sections(2, 4).build(),
+            ],
+            section_periods: Vec::new(),
+        };
+        let html = schedule_to_html(&schedule);
+        assert!(html.contains("课程A"));
+        assert!(html.contains("课程B"));
+    }
+
+    #[test]
+    fn schedule_to_html_keeps_overlapping_courses_with_no_common_week() {
+        // merge_conflicts leaves these as two separate Courses since they
+        // share no week; the grid still must not drop either one.
+        let schedule = CourseSchedule {
+            student_name: "张三".to_string(),
+            student_id: "123".to_string(),
+            semester: "2026春".to_string(),
+            courses: vec![
+                CourseBuilder::new("课程A").weeks("1-8周").sections(1, 3).build(),
+                CourseBuilder::new("课程B").weeks("9-16周").sections(2, 4).build(),
+            ],
+            section_periods: Vec::new(),
+        };
+        let html = schedule_to_html(&schedule);
+        assert!(html.contains("课程A"));
+        assert!(html.contains("课程B"));
+    }
+}
@@ -0,0 +1,199 @@
+use crate::pdf_reader::{Course, CourseSchedule};
+use crate::section_times::SectionTimes;
+use crate::weeks::expand_weeks;
+use chrono::{Duration, NaiveDate, Utc};
+
+/// Group sorted, deduped week numbers into (first, last) contiguous runs.
+fn contiguous_runs(weeks: &[u8]) -> Vec<(u8, u8)> {
+    let mut runs = Vec::new();
+    let mut iter = weeks.iter().peekable();
+    while let Some(&first) = iter.next() {
+        let mut last = first;
+        while let Some(&&next) = iter.peek() {
+            if next == last + 1 {
+                last = next;
+                iter.next();
+            } else {
+                break;
+            }
+        }
+        runs.push((first, last));
+    }
+    runs
+}
+
+/// Escape text per RFC 5545 (backslash, semicolon, comma, newline).
+fn escape_text(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold a single content line at 75 octets, per RFC 5545 section 3.1.
+fn fold_line(line: &str) -> String {
+    const MAX: usize = 75;
+    if line.len() <= MAX {
+        return format!("{}\r\n", line);
+    }
+
+    let bytes = line.as_bytes();
+    let mut out = String::new();
+    let mut start = 0;
+    let mut first = true;
+    while start < bytes.len() {
+        let budget = if first { MAX } else { MAX - 1 };
+        let mut end = (start + budget).min(bytes.len());
+        while end < bytes.len() && !line.is_char_boundary(end) {
+            end -= 1;
+        }
+        if !first {
+            out.push(' ');
+        }
+        out.push_str(&line[start..end]);
+        out.push_str("\r\n");
+        start = end;
+        first = false;
+    }
+    out
+}
+
+fn date_for_week(semester_start: NaiveDate, week: u8, day_of_week: u8) -> NaiveDate {
+    semester_start + Duration::days((week as i64 - 1) * 7 + (day_of_week as i64 - 1))
+}
+
+fn render_vevent(
+    course: &Course,
+    semester_start: NaiveDate,
+    times: &SectionTimes,
+    run: (u8, u8),
+    uid_seq: usize,
+) -> String {
+    let (first_week, last_week) = run;
+    let start_time = times.section_start(course.start_section);
+    let end_time = times.section_end(course.end_section);
+    let start_date = date_for_week(semester_start, first_week, course.day_of_week);
+    let dtstart = start_date.and_time(start_time);
+    let dtend = start_date.and_time(end_time);
+
+    let mut event = String::new();
+    event.push_str(&fold_line("BEGIN:VEVENT"));
+    event.push_str(&fold_line(&format!(
+        "UID:{}-{}-{}@course-schedule",
+        course.day_of_week, first_week, uid_seq
+    )));
+    event.push_str(&fold_line(&format!(
+        "DTSTAMP:{}",
+        Utc::now().format("%Y%m%dT%H%M%SZ")
+    )));
+    event.push_str(&fold_line(&format!(
+        "DTSTART:{}",
+        dtstart.format("%Y%m%dT%H%M%S")
+    )));
+    event.push_str(&fold_line(&format!(
+        "DTEND:{}",
+        dtend.format("%Y%m%dT%H%M%S")
+    )));
+    if last_week > first_week {
+        event.push_str(&fold_line(&format!(
+            "RRULE:FREQ=WEEKLY;COUNT={}",
+            last_week - first_week + 1
+        )));
+    }
+    event.push_str(&fold_line(&format!("SUMMARY:{}", escape_text(&course.name))));
+    if !course.location.is_empty() {
+        event.push_str(&fold_line(&format!(
+            "LOCATION:{}",
+            escape_text(&course.location)
+        )));
+    }
+    if !course.teacher.is_empty() {
+        event.push_str(&fold_line(&format!(
+            "COMMENT:教师: {}",
+            escape_text(&course.teacher)
+        )));
+    }
+    event.push_str(&fold_line("END:VEVENT"));
+    event
+}
+
+/// Turn a parsed `CourseSchedule` into an RFC 5545 VCALENDAR string.
+///
+/// `semester_start` is the date of the first Monday of the semester; week and
+/// day-of-week numbers in each `Course` are resolved against it. Courses with
+/// non-contiguous weeks (e.g. "6周,11周,14-18周") emit one VEVENT per
+/// contiguous run rather than a single RRULE, since Chinese schedules rarely
+/// fit a plain weekly recurrence.
+pub fn export_ics(schedule: &CourseSchedule, semester_start: NaiveDate) -> String {
+    let mut out = String::new();
+    out.push_str(&fold_line("BEGIN:VCALENDAR"));
+    out.push_str(&fold_line("VERSION:2.0"));
+    out.push_str(&fold_line("PRODID:-//course-schedule//CN"));
+    out.push_str(&fold_line("CALSCALE:GREGORIAN"));
+
+    let times = schedule.section_times();
+    let mut uid_seq = 0usize;
+    for course in &schedule.courses {
+        let weeks = expand_weeks(&course.weeks);
+        for run in contiguous_runs(&weeks) {
+            out.push_str(&render_vevent(course, semester_start, &times, run, uid_seq));
+            uid_seq += 1;
+        }
+    }
+
+    out.push_str(&fold_line("END:VCALENDAR"));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::CourseBuilder;
+
+    #[test]
+    fn groups_weeks_into_contiguous_runs() {
+        assert_eq!(
+            contiguous_runs(&[6, 11, 14, 15, 16, 18]),
+            vec![(6, 6), (11, 11), (14, 16), (18, 18)]
+        );
+    }
+
+    #[test]
+    fn escapes_commas_semicolons_and_newlines() {
+        assert_eq!(
+            escape_text("高数, 第3-4节; 备注\n续行"),
+            "高数\\, 第3-4节\\; 备注\\n续行"
+        );
+    }
+
+    #[test]
+    fn folds_long_lines_at_75_octets_with_leading_space_continuation() {
+        let long_value = "A".repeat(100);
+        let folded = fold_line(&format!("SUMMARY:{}", long_value));
+        let lines: Vec<&str> = folded.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(lines.len() > 1);
+        assert!(lines[0].len() <= 75);
+        for continuation in &lines[1..] {
+            assert!(continuation.starts_with(' '));
+            assert!(continuation.len() <= 75);
+        }
+    }
+
+    #[test]
+    fn short_lines_are_not_folded() {
+        let folded = fold_line("BEGIN:VEVENT");
+        assert_eq!(folded, "BEGIN:VEVENT\r\n");
+    }
+
+    #[test]
+    fn export_emits_one_vevent_per_contiguous_run() {
+        let mut schedule = CourseSchedule::new();
+        schedule
+            .courses
+            .push(CourseBuilder::new("高等数学").weeks("6周,11周,14-18周").build());
+
+        let ics = export_ics(&schedule, NaiveDate::from_ymd_opt(2026, 2, 23).unwrap());
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 3);
+        assert!(ics.contains("SUMMARY:高等数学"));
+    }
+}
@@ -1,5 +1,15 @@
+pub mod conflicts;
+pub mod csv;
+pub mod html;
+pub mod ics;
 pub mod pdf_reader;
+pub mod section_times;
+#[cfg(test)]
+mod test_support;
+pub mod weeks;
+pub mod xiaoai;
 
+use chrono::NaiveDate;
 use pdf_reader::{read_course_schedule_pdf, CourseSchedule};
 
 #[tauri::command]
@@ -7,11 +17,18 @@ fn parse_pdf(path: String) -> Result<CourseSchedule, String> {
     read_course_schedule_pdf(&path).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+fn export_schedule_ics(schedule: CourseSchedule, semester_start: String) -> Result<String, String> {
+    let semester_start = NaiveDate::parse_from_str(&semester_start, "%Y-%m-%d")
+        .map_err(|e| e.to_string())?;
+    Ok(ics::export_ics(&schedule, semester_start))
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
   tauri::Builder::default()
     .plugin(tauri_plugin_log::Builder::new().build())
-    .invoke_handler(tauri::generate_handler![parse_pdf])
+    .invoke_handler(tauri::generate_handler![parse_pdf, export_schedule_ics])
     .setup(|app| {
       if cfg!(debug_assertions) {
         app.handle().plugin(
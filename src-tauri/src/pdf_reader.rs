@@ -1,7 +1,9 @@
+use crate::section_times::{DayPeriod, SectionTimes};
+use crate::weeks::expand_weeks;
 use lopdf::Document;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Course {
     pub name: String,
     pub teacher: String,
@@ -13,12 +15,30 @@ pub struct Course {
     pub end_section: u8,    // 结束节次
 }
 
+impl Course {
+    /// Concrete week numbers this course meets, expanded from `weeks`.
+    pub fn weeks_expanded(&self) -> Vec<u8> {
+        expand_weeks(&self.weeks)
+    }
+
+    /// Inclusive section range, normalized so callers don't have to guard
+    /// against `start_section > end_section` (an occasional PDF parsing
+    /// quirk): the smaller bound always comes first.
+    pub fn section_range(&self) -> std::ops::RangeInclusive<u8> {
+        self.start_section.min(self.end_section)..=self.start_section.max(self.end_section)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CourseSchedule {
     pub student_name: String,
     pub student_id: String,
     pub semester: String,
     pub courses: Vec<Course>,
+    // Which 上午/下午/晚上 marker each section number was seen under, in the
+    // order encountered. Empty for schedules parsed before this was tracked.
+    #[serde(default)]
+    pub section_periods: Vec<(u8, DayPeriod)>,
 }
 
 impl CourseSchedule {
@@ -28,6 +48,18 @@ impl CourseSchedule {
             student_id: String::new(),
             semester: String::new(),
             courses: Vec::new(),
+            section_periods: Vec::new(),
+        }
+    }
+
+    /// Section->clock-time table for this schedule: the default 12-section
+    /// table, re-derived from the observed period markers if any were
+    /// captured while parsing.
+    pub fn section_times(&self) -> SectionTimes {
+        if self.section_periods.is_empty() {
+            SectionTimes::default()
+        } else {
+            SectionTimes::from_observed_periods(&self.section_periods)
         }
     }
 }
@@ -128,18 +160,33 @@ fn parse_schedule_text(text: &str, schedule: &mut CourseSchedule) -> Result<(),
     // 从表头后开始解析课程
     let mut i = start_idx + day_count;
     let mut current_section = 0u8;
+    let mut current_period = DayPeriod::Morning;
     let mut courses_in_section: Vec<(u8, String)> = Vec::new(); // (day, course_text)
 
     while i < lines.len() {
         let line = lines[i].trim();
 
+        // 上午/下午/晚上标记行：记录当前时间段，供 section_periods 使用
+        if line.contains("上午") {
+            current_period = DayPeriod::Morning;
+            i += 1;
+            continue;
+        }
+        if line.contains("下午") {
+            current_period = DayPeriod::Afternoon;
+            i += 1;
+            continue;
+        }
+        if line.contains("晚上") {
+            current_period = DayPeriod::Evening;
+            i += 1;
+            continue;
+        }
+
         // 跳过空行和特殊行
         if line.is_empty() ||
            line.contains("其他课程") ||
            line.contains("打印时间") ||
-           line.contains("上午") ||
-           line.contains("下午") ||
-           line.contains("晚上") ||
            line.contains("时间段") {
             i += 1;
             continue;
@@ -155,6 +202,7 @@ fn parse_schedule_text(text: &str, schedule: &mut CourseSchedule) -> Result<(),
             if let Ok(section) = line.parse::<u8>() {
                 if section >= 1 && section <= 12 {
                     current_section = section;
+                    schedule.section_periods.push((section, current_period));
                 }
             }
             i += 1;
@@ -0,0 +1,200 @@
+//! Configurable mapping of teaching sections to concrete clock times.
+
+use chrono::{Duration, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Which part of the day a teaching section falls into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DayPeriod {
+    Morning,
+    Afternoon,
+    Evening,
+}
+
+fn period_window(period: DayPeriod) -> (NaiveTime, NaiveTime) {
+    match period {
+        DayPeriod::Morning => (
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(12, 10, 0).unwrap(),
+        ),
+        DayPeriod::Afternoon => (
+            NaiveTime::from_hms_opt(14, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+        ),
+        DayPeriod::Evening => (
+            NaiveTime::from_hms_opt(19, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(22, 30, 0).unwrap(),
+        ),
+    }
+}
+
+fn default_table() -> HashMap<u8, (NaiveTime, NaiveTime)> {
+    let slots = [
+        (1, 8, 0, 8, 45),
+        (2, 8, 55, 9, 40),
+        (3, 10, 0, 10, 45),
+        (4, 10, 55, 11, 40),
+        (5, 14, 0, 14, 45),
+        (6, 14, 55, 15, 40),
+        (7, 16, 0, 16, 45),
+        (8, 16, 55, 17, 40),
+        (9, 19, 0, 19, 45),
+        (10, 19, 55, 20, 40),
+        (11, 20, 50, 21, 35),
+        (12, 21, 45, 22, 30),
+    ];
+    slots
+        .into_iter()
+        .map(|(section, sh, sm, eh, em)| {
+            (
+                section,
+                (
+                    NaiveTime::from_hms_opt(sh, sm, 0).unwrap(),
+                    NaiveTime::from_hms_opt(eh, em, 0).unwrap(),
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Maps teaching sections (1..=12) to concrete `(start, end)` clock times.
+///
+/// Schools differ on where the 上午/下午/晚上 boundaries fall and how many
+/// sections each period has, so the default table can be overridden per
+/// section, or re-derived wholesale from a schedule's observed section-to-
+/// period markers via [`SectionTimes::from_observed_periods`].
+#[derive(Debug, Clone)]
+pub struct SectionTimes {
+    table: HashMap<u8, (NaiveTime, NaiveTime)>,
+}
+
+impl Default for SectionTimes {
+    fn default() -> Self {
+        SectionTimes {
+            table: default_table(),
+        }
+    }
+}
+
+impl SectionTimes {
+    /// Override specific sections' clock times, e.g. loaded from a school's
+    /// own schedule configuration.
+    pub fn with_overrides(mut self, overrides: &[(u8, NaiveTime, NaiveTime)]) -> Self {
+        for &(section, start, end) in overrides {
+            self.table.insert(section, (start, end));
+        }
+        self
+    }
+
+    pub fn section_start(&self, section: u8) -> NaiveTime {
+        self.table
+            .get(&section)
+            .map(|(start, _)| *start)
+            .unwrap_or_else(|| NaiveTime::from_hms_opt(0, 0, 0).unwrap())
+    }
+
+    pub fn section_end(&self, section: u8) -> NaiveTime {
+        self.table
+            .get(&section)
+            .map(|(_, end)| *end)
+            .unwrap_or_else(|| NaiveTime::from_hms_opt(0, 45, 0).unwrap())
+    }
+
+    /// Re-derive clock times from a schedule's observed section-to-period
+    /// mapping, evenly spacing each period's sections across its usual
+    /// morning/afternoon/evening window. Sections absent from `periods` keep
+    /// their default time, so a schedule with a non-standard number of daily
+    /// sections still gets a sensible table.
+    pub fn from_observed_periods(periods: &[(u8, DayPeriod)]) -> Self {
+        let mut by_period: HashMap<DayPeriod, Vec<u8>> = HashMap::new();
+        for &(section, period) in periods {
+            by_period.entry(period).or_default().push(section);
+        }
+
+        let mut table = default_table();
+        for (period, mut sections) in by_period {
+            sections.sort_unstable();
+            sections.dedup();
+            let count = sections.len() as i64;
+            if count == 0 {
+                continue;
+            }
+
+            let (window_start, window_end) = period_window(period);
+            let total_minutes = (window_end - window_start).num_minutes();
+            let class_minutes = (total_minutes / count).clamp(1, 45);
+            let gap_minutes = if count > 1 {
+                (total_minutes - class_minutes * count) / (count - 1)
+            } else {
+                0
+            };
+
+            let mut cursor = window_start;
+            for section in sections {
+                let start = cursor;
+                let end = start + Duration::minutes(class_minutes);
+                table.insert(section, (start, end));
+                cursor = end + Duration::minutes(gap_minutes);
+            }
+        }
+
+        SectionTimes { table }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_table_covers_all_sections() {
+        let times = SectionTimes::default();
+        for section in 1..=12u8 {
+            assert!(times.table.contains_key(&section));
+        }
+        assert_eq!(
+            times.section_start(1),
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap()
+        );
+        assert_eq!(
+            times.section_end(12),
+            NaiveTime::from_hms_opt(22, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn overrides_replace_only_named_sections() {
+        let times = SectionTimes::default().with_overrides(&[(
+            1,
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(9, 45, 0).unwrap(),
+        )]);
+        assert_eq!(times.section_start(1), NaiveTime::from_hms_opt(9, 0, 0).unwrap());
+        assert_eq!(
+            times.section_start(2),
+            NaiveTime::from_hms_opt(8, 55, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn observed_periods_reflow_a_three_section_morning() {
+        let periods = vec![
+            (1, DayPeriod::Morning),
+            (2, DayPeriod::Morning),
+            (3, DayPeriod::Morning),
+        ];
+        let times = SectionTimes::from_observed_periods(&periods);
+        assert_eq!(
+            times.section_start(1),
+            NaiveTime::from_hms_opt(8, 0, 0).unwrap()
+        );
+        assert!(times.section_start(2) > times.section_start(1));
+        assert!(times.section_start(3) > times.section_start(2));
+        // Sections outside the observed periods keep their default time.
+        assert_eq!(
+            times.section_start(5),
+            NaiveTime::from_hms_opt(14, 0, 0).unwrap()
+        );
+    }
+}
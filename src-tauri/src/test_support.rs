@@ -0,0 +1,71 @@
+//! Shared `Course` fixture builder for tests. Several modules' test suites
+//! need a throwaway `Course` with sensible placeholder values and a couple
+//! of fields varied per-case; this collects that into one builder instead of
+//! each module re-deriving its own near-identical factory function.
+
+#![cfg(test)]
+
+use crate::pdf_reader::Course;
+
+pub struct CourseBuilder {
+    name: String,
+    teacher: String,
+    location: String,
+    weeks: String,
+    day_of_week: u8,
+    start_section: u8,
+    end_section: u8,
+}
+
+impl CourseBuilder {
+    pub fn new(name: &str) -> Self {
+        CourseBuilder {
+            name: name.to_string(),
+            teacher: "王老师".to_string(),
+            location: "A101".to_string(),
+            weeks: "1-18周".to_string(),
+            day_of_week: 1,
+            start_section: 1,
+            end_section: 2,
+        }
+    }
+
+    pub fn teacher(mut self, teacher: &str) -> Self {
+        self.teacher = teacher.to_string();
+        self
+    }
+
+    pub fn location(mut self, location: &str) -> Self {
+        self.location = location.to_string();
+        self
+    }
+
+    pub fn weeks(mut self, weeks: &str) -> Self {
+        self.weeks = weeks.to_string();
+        self
+    }
+
+    pub fn day(mut self, day_of_week: u8) -> Self {
+        self.day_of_week = day_of_week;
+        self
+    }
+
+    pub fn sections(mut self, start_section: u8, end_section: u8) -> Self {
+        self.start_section = start_section;
+        self.end_section = end_section;
+        self
+    }
+
+    pub fn build(self) -> Course {
+        Course {
+            name: self.name,
+            teacher: self.teacher,
+            location: self.location,
+            time_slot: format!("{}-{}节", self.start_section, self.end_section),
+            weeks: self.weeks,
+            day_of_week: self.day_of_week,
+            start_section: self.start_section,
+            end_section: self.end_section,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Expansion of the raw `周次` (week range) strings produced by the PDF
+//! parser into concrete week numbers.
+
+/// Expand a raw `weeks` string such as `"6-8周(双),9-18周"` into sorted,
+/// deduped week numbers.
+///
+/// Each comma-separated segment is either a single week (`N周`) or a range
+/// (`A-B周`), optionally suffixed with `(单)` (odd weeks only) or `(双)`
+/// (even weeks only), in either half-width or full-width parentheses.
+/// Stray whitespace and a missing trailing `周` on intermediate segments are
+/// tolerated.
+pub fn expand_weeks(weeks: &str) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    for segment in weeks.split(',') {
+        let segment = segment.trim();
+        if segment.is_empty() {
+            continue;
+        }
+
+        let (range_part, parity) = if let Some(body) = segment
+            .strip_suffix("(双)")
+            .or_else(|| segment.strip_suffix("（双）"))
+        {
+            (body, Some(0u8))
+        } else if let Some(body) = segment
+            .strip_suffix("(单)")
+            .or_else(|| segment.strip_suffix("（单）"))
+        {
+            (body, Some(1u8))
+        } else {
+            (segment, None)
+        };
+        let range_part = range_part.trim().trim_end_matches('周').trim();
+        if range_part.is_empty() {
+            continue;
+        }
+
+        let (start, end) = if let Some(dash_pos) = range_part.find('-') {
+            let start = match range_part[..dash_pos].trim().parse::<u8>() {
+                Ok(n) => n,
+                Err(_) => continue,
+            };
+            let end = range_part[dash_pos + 1..]
+                .trim()
+                .parse::<u8>()
+                .unwrap_or(start);
+            (start, end)
+        } else {
+            match range_part.parse::<u8>() {
+                Ok(n) => (n, n),
+                Err(_) => continue,
+            }
+        };
+
+        for week in start..=end {
+            if let Some(parity) = parity {
+                if week % 2 != parity {
+                    continue;
+                }
+            }
+            out.push(week);
+        }
+    }
+
+    out.sort_unstable();
+    out.dedup();
+    out
+}
+
+/// Collapse sorted, deduped week numbers back into a `weeks`-style string,
+/// joining contiguous runs as `"A-B周"` and isolated weeks as `"N周"`.
+///
+/// This is the inverse of [`expand_weeks`], used when merging courses whose
+/// combined week set no longer matches either original raw string.
+pub fn format_weeks(weeks: &[u8]) -> String {
+    let mut segments = Vec::new();
+    let mut iter = weeks.iter().copied().peekable();
+
+    while let Some(start) = iter.next() {
+        let mut end = start;
+        while iter.peek() == Some(&(end + 1)) {
+            end = iter.next().unwrap();
+        }
+        if end == start {
+            segments.push(format!("{}周", start));
+        } else {
+            segments.push(format!("{}-{}周", start, end));
+        }
+    }
+
+    segments.join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_plain_range() {
+        assert_eq!(expand_weeks("9-18周"), (9..=18).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn expands_even_and_odd_markers() {
+        assert_eq!(expand_weeks("6-8周(双)"), vec![6, 8]);
+        assert_eq!(expand_weeks("6-8周(单)"), vec![7]);
+    }
+
+    #[test]
+    fn expands_full_width_parens() {
+        assert_eq!(expand_weeks("6-8周（双）"), vec![6, 8]);
+    }
+
+    #[test]
+    fn dedupes_and_sorts_mixed_segments() {
+        assert_eq!(
+            expand_weeks("6周,11周,14-18周,15周"),
+            vec![6, 11, 14, 15, 16, 17, 18]
+        );
+    }
+
+    #[test]
+    fn tolerates_missing_trailing_glyph_and_whitespace() {
+        assert_eq!(expand_weeks(" 6-8 (双) , 11 周 "), vec![6, 8, 11]);
+    }
+
+    #[test]
+    fn format_weeks_collapses_runs_and_singles() {
+        assert_eq!(format_weeks(&[1, 2, 3, 5, 7, 8]), "1-3周,5周,7-8周");
+    }
+
+    #[test]
+    fn format_weeks_round_trips_through_expand_weeks() {
+        let weeks = expand_weeks("6-8周(双),9-18周");
+        assert_eq!(expand_weeks(&format_weeks(&weeks)), weeks);
+    }
+}
@@ -0,0 +1,112 @@
+//! Export to the 小爱课程表 (XiaoAi) importer JSON schema.
+
+use crate::pdf_reader::CourseSchedule;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct XiaoAiCourse {
+    pub name: String,
+    pub teacher: String,
+    pub position: String,
+    pub day: u8,
+    pub sections: Vec<u8>,
+    pub weeks: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct XiaoAiSemester {
+    pub student_name: String,
+    pub semester: String,
+    pub total_weeks: u8,
+    pub first_week_start: NaiveDate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct XiaoAiExport {
+    pub semester: XiaoAiSemester,
+    pub courses: Vec<XiaoAiCourse>,
+}
+
+/// Build the XiaoAi importer shape for a parsed schedule.
+///
+/// `first_week_start` is the date week 1 begins on; `total_weeks` is derived
+/// from the highest week number any course meets in.
+pub fn export_xiaoai(schedule: &CourseSchedule, first_week_start: NaiveDate) -> XiaoAiExport {
+    let courses = schedule
+        .courses
+        .iter()
+        .map(|course| XiaoAiCourse {
+            name: course.name.clone(),
+            teacher: course.teacher.clone(),
+            position: course.location.clone(),
+            day: course.day_of_week,
+            sections: course.section_range().collect(),
+            weeks: course.weeks_expanded(),
+        })
+        .collect();
+
+    let total_weeks = schedule
+        .courses
+        .iter()
+        .flat_map(|course| course.weeks_expanded())
+        .max()
+        .unwrap_or(0);
+
+    XiaoAiExport {
+        semester: XiaoAiSemester {
+            student_name: schedule.student_name.clone(),
+            semester: schedule.semester.clone(),
+            total_weeks,
+            first_week_start,
+        },
+        courses,
+    }
+}
+
+/// Serialize the XiaoAi export shape to a pretty-printed JSON string.
+pub fn export_xiaoai_json(
+    schedule: &CourseSchedule,
+    first_week_start: NaiveDate,
+) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&export_xiaoai(schedule, first_week_start))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::CourseBuilder;
+
+    #[test]
+    fn builds_course_and_semester_shape() {
+        let mut schedule = CourseSchedule::new();
+        schedule.student_name = "张三".to_string();
+        schedule.semester = "2026春".to_string();
+        schedule
+            .courses
+            .push(CourseBuilder::new("高等数学").weeks("1-8周(单)").build());
+
+        let export = export_xiaoai(&schedule, NaiveDate::from_ymd_opt(2026, 2, 23).unwrap());
+
+        assert_eq!(export.semester.student_name, "张三");
+        assert_eq!(export.semester.total_weeks, 7);
+        assert_eq!(export.courses.len(), 1);
+        assert_eq!(export.courses[0].sections, vec![1, 2]);
+        assert_eq!(export.courses[0].weeks, vec![1, 3, 5, 7]);
+    }
+
+    #[test]
+    fn normalizes_inverted_section_range() {
+        let mut schedule = CourseSchedule::new();
+        schedule.courses.push(
+            CourseBuilder::new("异常课程")
+                .weeks("1-2周")
+                .sections(5, 3)
+                .build(),
+        );
+
+        let export = export_xiaoai(&schedule, NaiveDate::from_ymd_opt(2026, 2, 23).unwrap());
+
+        assert_eq!(export.courses[0].sections, vec![3, 4, 5]);
+    }
+}